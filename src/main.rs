@@ -4,6 +4,7 @@ use iced::{mouse, Color, Element, Length, Point, Rectangle, Theme, Size, alignme
 use iced::font::{Weight};
 use chrono::{NaiveDate, Utc, Duration, Datelike, Weekday};
 use serde::{Serialize, Deserialize};
+use std::collections::HashMap;
 use std::fs;
 use std::time::{Instant, Duration as StdDuration};
 
@@ -20,12 +21,80 @@ struct SavedEntry {
     date: NaiveDate,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ViewMode {
+    Graph,
+    Values,
+}
+
+impl std::fmt::Display for ViewMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViewMode::Graph => write!(f, "Graph"),
+            ViewMode::Values => write!(f, "Values"),
+        }
+    }
+}
+
 impl std::fmt::Display for SavedEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ({})", self.name, self.date)
     }
 }
 
+/// A user-annotated real-life event spanning one or more days, drawn as a band across the chart.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+struct CalendarEvent {
+    text: String,
+    begin: NaiveDate,
+    end: NaiveDate,
+}
+
+impl CalendarEvent {
+    /// Whether this event's range intersects the visible `[first, last]` window.
+    fn is_in_days(&self, first: NaiveDate, last: NaiveDate) -> bool {
+        self.begin <= last && self.end >= first
+    }
+
+    fn span_days(&self) -> i64 {
+        (self.end - self.begin).num_days() + 1
+    }
+}
+
+/// A selectable UTC offset, in minutes, for converting "now" to the user's civil day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct TzOffset(i32);
+
+impl std::fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sign = if self.0 < 0 { "-" } else { "+" };
+        let abs = self.0.abs();
+        let (hh, mm) = (abs / 60, abs % 60);
+        if mm == 0 {
+            write!(f, "UTC {}{}", sign, hh)
+        } else {
+            write!(f, "UTC {}{}:{:02}", sign, hh, mm)
+        }
+    }
+}
+
+/// Real-world UTC offsets, including the half- and 45-minute zones.
+const TZ_OFFSETS: [TzOffset; 38] = [
+    TzOffset(-720), TzOffset(-660), TzOffset(-600), TzOffset(-570), TzOffset(-540),
+    TzOffset(-480), TzOffset(-420), TzOffset(-360), TzOffset(-300), TzOffset(-240),
+    TzOffset(-210), TzOffset(-180), TzOffset(-120), TzOffset(-60), TzOffset(0),
+    TzOffset(60), TzOffset(120), TzOffset(180), TzOffset(210), TzOffset(240),
+    TzOffset(270), TzOffset(300), TzOffset(330), TzOffset(345), TzOffset(360),
+    TzOffset(390), TzOffset(420), TzOffset(480), TzOffset(525), TzOffset(540),
+    TzOffset(570), TzOffset(600), TzOffset(630), TzOffset(660), TzOffset(720),
+    TzOffset(765), TzOffset(780), TzOffset(840),
+];
+
+#[derive(Serialize, Deserialize, Default)]
+struct AppSettings {
+    tz_offset_minutes: i32,
+}
+
 struct BiorhythmApp {
     name_input: String,
     date_input: String,
@@ -35,6 +104,18 @@ struct BiorhythmApp {
     day_offset: i32,
     rolling_direction: Option<i32>,
     last_tick: Instant,
+    view_mode: ViewMode,
+    window_days: u32,
+    event_text_input: String,
+    event_begin_input: String,
+    event_end_input: String,
+    events: Vec<CalendarEvent>,
+    tz_offset_minutes: i32,
+    journal: HashMap<NaiveDate, [i8; 3]>,
+    rating_physical_input: String,
+    rating_emotional_input: String,
+    rating_intellectual_input: String,
+    active_entries: Vec<SavedEntry>,
 }
 
 #[derive(Debug, Clone)]
@@ -51,6 +132,18 @@ enum Message {
     EventOccurred(Event),
     MouseWheelScrolled(f32),
     GoToDate(i32),
+    SetViewMode(ViewMode),
+    WindowChanged(u32),
+    EventTextChanged(String),
+    EventBeginChanged(String),
+    EventEndChanged(String),
+    SaveEvent,
+    TimezoneChanged(TzOffset),
+    RatingPhysicalChanged(String),
+    RatingEmotionalChanged(String),
+    RatingIntellectualChanged(String),
+    LogRating,
+    ToggleActiveEntry(SavedEntry),
 }
 
 impl BiorhythmApp {
@@ -101,6 +194,51 @@ impl BiorhythmApp {
                 self.day_offset = offset;
                 self.chart_cache.clear();
             }
+            Message::SetViewMode(mode) => self.view_mode = mode,
+            Message::WindowChanged(days) => { self.window_days = days.clamp(7, 180); self.chart_cache.clear(); },
+            Message::EventTextChanged(t) => self.event_text_input = t,
+            Message::EventBeginChanged(d) => self.event_begin_input = d,
+            Message::EventEndChanged(d) => self.event_end_input = d,
+            Message::SaveEvent => {
+                let begin = NaiveDate::parse_from_str(&self.event_begin_input, "%Y-%m-%d");
+                let end = NaiveDate::parse_from_str(&self.event_end_input, "%Y-%m-%d");
+                if let (Ok(begin), Ok(end)) = (begin, end) {
+                    if !self.event_text_input.is_empty() && begin <= end {
+                        self.events.push(CalendarEvent { text: self.event_text_input.clone(), begin, end });
+                        let _ = fs::write("events.json", serde_json::to_string(&self.events).unwrap());
+                        self.chart_cache.clear();
+                    }
+                }
+            },
+            Message::TimezoneChanged(tz) => {
+                self.tz_offset_minutes = tz.0;
+                self.chart_cache.clear();
+                let _ = fs::write("settings.json", serde_json::to_string(&AppSettings { tz_offset_minutes: tz.0 }).unwrap());
+            },
+            Message::RatingPhysicalChanged(v) => self.rating_physical_input = v,
+            Message::RatingEmotionalChanged(v) => self.rating_emotional_input = v,
+            Message::RatingIntellectualChanged(v) => self.rating_intellectual_input = v,
+            Message::LogRating => {
+                let p = self.rating_physical_input.parse::<i8>();
+                let e = self.rating_emotional_input.parse::<i8>();
+                let i = self.rating_intellectual_input.parse::<i8>();
+                if let (Ok(p), Ok(e), Ok(i)) = (p, e, i) {
+                    let today = self.today();
+                    let target_date = today + Duration::days(self.day_offset as i64);
+                    let clamp = |v: i8| v.clamp(-100, 100);
+                    self.journal.insert(target_date, [clamp(p), clamp(e), clamp(i)]);
+                    let _ = fs::write("journal.json", serde_json::to_string(&self.journal).unwrap());
+                    self.chart_cache.clear();
+                }
+            },
+            Message::ToggleActiveEntry(entry) => {
+                if let Some(pos) = self.active_entries.iter().position(|e| e == &entry) {
+                    self.active_entries.remove(pos);
+                } else {
+                    self.active_entries.push(entry);
+                }
+                self.chart_cache.clear();
+            },
             Message::EventOccurred(event) => {
                 match event {
                     Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
@@ -119,6 +257,11 @@ impl BiorhythmApp {
         }
     }
 
+    /// "Today" in the user's chosen civil timezone, not the server/OS UTC day.
+    fn today(&self) -> NaiveDate {
+        (Utc::now().naive_utc() + Duration::minutes(self.tz_offset_minutes as i64)).date()
+    }
+
     fn subscription(&self) -> Subscription<Message> {
         let events = iced::event::listen().map(Message::EventOccurred);
         if self.rolling_direction.is_some() {
@@ -129,7 +272,7 @@ impl BiorhythmApp {
     }
 
     fn view(&self) -> Element<'_, Message> {
-        let today = Utc::now().naive_utc().date();
+        let today = self.today();
         let target_date = today + Duration::days(self.day_offset as i64);
         let target_year = target_date.format("%Y").to_string();
 
@@ -138,6 +281,10 @@ impl BiorhythmApp {
             text_input("YYYY-MM-DD", &self.date_input).on_input(Message::DateChanged).width(102),
             button("Save").on_press(Message::SaveEntry),
             pick_list(&self.saved_entries[..], self.selected_entry.clone(), Message::EntrySelected).placeholder("Select profile..."),
+            horizontal_space(),
+            button("Graph").on_press(Message::SetViewMode(ViewMode::Graph)).style(if self.view_mode == ViewMode::Graph { button::primary } else { button::secondary }),
+            button("Values").on_press(Message::SetViewMode(ViewMode::Values)).style(if self.view_mode == ViewMode::Values { button::primary } else { button::secondary }),
+            pick_list(&TZ_OFFSETS[..], Some(TzOffset(self.tz_offset_minutes)), Message::TimezoneChanged),
         ].spacing(10);
 
         let nav_row = row![
@@ -149,6 +296,39 @@ impl BiorhythmApp {
             button("Today").on_press(Message::ResetOffset),
         ].spacing(10).align_y(alignment::Vertical::Center);
 
+        let window_row = row![
+            text("Window:").size(14),
+            slider(7..=180, self.window_days, Message::WindowChanged).width(200),
+            text(format!("{} days", self.window_days)).size(14),
+        ].spacing(10).align_y(alignment::Vertical::Center);
+
+        let events_row = row![
+            text_input("Event text", &self.event_text_input).on_input(Message::EventTextChanged),
+            text_input("Begin YYYY-MM-DD", &self.event_begin_input).on_input(Message::EventBeginChanged).width(130),
+            text_input("End YYYY-MM-DD", &self.event_end_input).on_input(Message::EventEndChanged).width(130),
+            button("Add Event").on_press(Message::SaveEvent),
+        ].spacing(10);
+
+        let journal_row = row![
+            text("Log rating for target day:").size(14),
+            text_input("Physical -100..100", &self.rating_physical_input).on_input(Message::RatingPhysicalChanged).width(130),
+            text_input("Emotional -100..100", &self.rating_emotional_input).on_input(Message::RatingEmotionalChanged).width(130),
+            text_input("Intellectual -100..100", &self.rating_intellectual_input).on_input(Message::RatingIntellectualChanged).width(140),
+            button("Log").on_press(Message::LogRating),
+        ].spacing(10).align_y(alignment::Vertical::Center);
+
+        let compare_row = self.saved_entries.iter().fold(
+            row![text("Compare:").size(14)].spacing(8).align_y(alignment::Vertical::Center),
+            |r, entry| {
+                let active = self.active_entries.contains(entry);
+                r.push(
+                    button(text(entry.name.clone()).size(13))
+                        .style(if active { button::primary } else { button::secondary })
+                        .on_press(Message::ToggleActiveEntry(entry.clone()))
+                )
+            },
+        );
+
         let sidebar = self.build_analysis_sidebar();
 
         // The header row containing "Critical Days" and the Year aligned with the center of the chart
@@ -172,13 +352,22 @@ impl BiorhythmApp {
             .width(Length::FillPortion(1))
         ].spacing(20);
 
+        let main_view: Element<'_, Message> = match self.view_mode {
+            ViewMode::Graph => Canvas::new(self).width(Length::Fill).height(Length::Fill).into(),
+            ViewMode::Values => self.build_values_table().into(),
+        };
+
         container(column![
             controls,
             nav_row,
+            window_row,
+            events_row,
+            journal_row,
+            compare_row,
             chart_header,
             row![
                 column![
-                    Canvas::new(self).width(Length::Fill).height(Length::Fill),
+                    main_view,
                     row![
                         text(format!("Timeline Offset: {} days", self.day_offset)).size(14),
                         horizontal_space(),
@@ -198,22 +387,15 @@ impl BiorhythmApp {
         ].spacing(10);
 
         if let Ok(birthday) = NaiveDate::parse_from_str(&self.date_input, "%Y-%m-%d") {
-            let today = Utc::now().naive_utc().date();
+            let today = self.today();
             let mut items = Vec::new();
 
-            for i in (self.day_offset - 5)..(self.day_offset + 25) {
+            let before = (self.window_days / 6) as i32;
+            let after = self.window_days as i32 - before;
+            for i in (self.day_offset - before)..(self.day_offset + after) {
                 let date = today + Duration::days(i as i64);
                 let days_since = date.signed_duration_since(birthday).num_days() as f64;
-
-                let mut active_crit = Vec::new();
-                for (period, name) in [(23.0, "P"), (28.0, "E"), (33.0, "I")] {
-                    let val_now = ((2.0 * std::f64::consts::PI * days_since) / period).sin();
-                    let val_prev = ((2.0 * std::f64::consts::PI * (days_since - 1.0)) / period).sin();
-
-                    if (val_now >= 0.0 && val_prev < 0.0) || (val_now <= 0.0 && val_prev > 0.0) {
-                        active_crit.push(name);
-                    }
-                }
+                let active_crit = Self::cycle_crossings(days_since);
 
                 if !active_crit.is_empty() {
                     items.push((i, date, active_crit));
@@ -242,8 +424,207 @@ impl BiorhythmApp {
 
             analysis = analysis.push(scrollable(list));
         }
+
+        analysis = analysis.push(self.build_correlation_panel());
+        analysis = analysis.push(self.build_compatibility_panel());
         analysis
     }
+
+    /// Per-cycle compatibility between the primary profile and each active comparison profile,
+    /// plus a per-day list (mirroring the zero-crossing list) so specific strong/critical dates
+    /// can be jumped to. The headline per-cycle numbers are the window-average of the exact same
+    /// per-day series the list displays, so the two can never disagree.
+    fn build_compatibility_panel(&self) -> Column<'_, Message> {
+        let mut panel = column![
+            text("Compatibility").size(12).color(Color::from_rgb(0.5, 0.5, 0.5))
+        ].spacing(6);
+
+        if let Ok(birthday) = NaiveDate::parse_from_str(&self.date_input, "%Y-%m-%d") {
+            if !self.active_entries.is_empty() {
+                let today = self.today();
+                let target_date = today + Duration::days(self.day_offset as i64);
+                let view_start = target_date - Duration::days(self.window_days as i64 / 2);
+
+                for entry in &self.active_entries {
+                    panel = panel.push(text(format!("vs {}", entry.name)).size(13).font(Font { weight: Weight::Bold, ..Font::DEFAULT }));
+
+                    let series = self.compute_compatibility_series(birthday, entry.date, view_start, today);
+
+                    let labels = ["Physical", "Emotional", "Intellectual"];
+                    for (idx, label) in labels.into_iter().enumerate() {
+                        let avg = series.iter().map(|(_, _, cycles)| cycles[idx]).sum::<f64>() / series.len() as f64;
+                        panel = panel.push(text(format!("  {}: {:+.0}%", label, avg)).size(12));
+                    }
+
+                    let list = series.into_iter().fold(column![].spacing(4), |col, (offset, date, cycles)| {
+                        let composite = cycles.iter().sum::<f64>() / cycles.len() as f64;
+                        let color = if composite >= 50.0 {
+                            Color::from_rgb(0.2, 1.0, 0.5)
+                        } else if composite <= -50.0 {
+                            Color::from_rgb(1.0, 0.3, 0.3)
+                        } else {
+                            Color::from_rgb(0.7, 0.7, 0.7)
+                        };
+
+                        col.push(
+                            button(
+                                row![
+                                    text(date.format("%b %d").to_string()).size(12).width(55),
+                                    text(format!("{:+.0}%", composite)).color(color).size(12),
+                                ].spacing(10)
+                            )
+                            .width(Length::Fill)
+                            .on_press(Message::GoToDate(offset))
+                            .style(button::secondary)
+                        )
+                    });
+                    panel = panel.push(scrollable(list).height(120));
+                }
+            }
+        }
+        panel
+    }
+
+    /// Per-day, per-cycle compatibility between two birthdays as `100 * sin(a) * sin(b)` — positive
+    /// when both profiles run the same direction that day, negative when they oppose. This is the
+    /// single source of truth: the sidebar's per-cycle headline is the window-average of this same
+    /// series, and the drill-down list below it shows each day's three-cycle average of it, so both always agree.
+    fn compute_compatibility_series(&self, birthday_a: NaiveDate, birthday_b: NaiveDate, view_start: NaiveDate, today: NaiveDate) -> Vec<(i32, NaiveDate, [f64; 3])> {
+        let periods = [23.0, 28.0, 33.0];
+        (0..self.window_days).map(|i| {
+            let date = view_start + Duration::days(i as i64);
+            let days_a = date.signed_duration_since(birthday_a).num_days() as f64;
+            let days_b = date.signed_duration_since(birthday_b).num_days() as f64;
+
+            let mut cycles = [0.0; 3];
+            for (idx, period) in periods.into_iter().enumerate() {
+                let val_a = ((2.0 * std::f64::consts::PI * days_a) / period).sin();
+                let val_b = ((2.0 * std::f64::consts::PI * days_b) / period).sin();
+                cycles[idx] = 100.0 * val_a * val_b;
+            }
+
+            let offset = date.signed_duration_since(today).num_days() as i32;
+            (offset, date, cycles)
+        }).collect()
+    }
+
+    /// Pearson correlation between each cycle's predicted curve and the user's logged ratings.
+    fn build_correlation_panel(&self) -> Column<'_, Message> {
+        let mut panel = column![
+            text("Prediction vs. journal (r)").size(12).color(Color::from_rgb(0.5, 0.5, 0.5))
+        ].spacing(6);
+
+        if let Ok(birthday) = NaiveDate::parse_from_str(&self.date_input, "%Y-%m-%d") {
+            let cycles = [(23.0, "Physical", 0usize), (28.0, "Emotional", 1usize), (33.0, "Intellectual", 2usize)];
+            for (period, label, idx) in cycles {
+                let mut predicted = Vec::new();
+                let mut actual = Vec::new();
+                for (date, ratings) in &self.journal {
+                    let days_since = date.signed_duration_since(birthday).num_days() as f64;
+                    let val = ((2.0 * std::f64::consts::PI * days_since) / period).sin();
+                    predicted.push(val * 100.0);
+                    actual.push(ratings[idx] as f64);
+                }
+
+                let text_value = match Self::pearson_correlation(&predicted, &actual) {
+                    Some(r) => format!("{}: r = {:+.2}", label, r),
+                    None => format!("{}: not enough data", label),
+                };
+                panel = panel.push(text(text_value).size(13));
+            }
+        }
+        panel
+    }
+
+    fn pearson_correlation(xs: &[f64], ys: &[f64]) -> Option<f64> {
+        if xs.len() < 2 || xs.len() != ys.len() {
+            return None;
+        }
+
+        let n = xs.len() as f64;
+        let x_mean = xs.iter().sum::<f64>() / n;
+        let y_mean = ys.iter().sum::<f64>() / n;
+
+        let mut cov = 0.0;
+        let mut x_var = 0.0;
+        let mut y_var = 0.0;
+        for (x, y) in xs.iter().zip(ys) {
+            cov += (x - x_mean) * (y - y_mean);
+            x_var += (x - x_mean).powi(2);
+            y_var += (y - y_mean).powi(2);
+        }
+
+        if x_var == 0.0 || y_var == 0.0 {
+            return None;
+        }
+        Some(cov / (x_var.sqrt() * y_var.sqrt()))
+    }
+
+    /// Cycle names ("P"/"E"/"I") whose sine value crosses zero between `days_since - 1` and `days_since`.
+    fn cycle_crossings(days_since: f64) -> Vec<&'static str> {
+        let mut active = Vec::new();
+        for (period, name) in [(23.0, "P"), (28.0, "E"), (33.0, "I")] {
+            let val_now = ((2.0 * std::f64::consts::PI * days_since) / period).sin();
+            let val_prev = ((2.0 * std::f64::consts::PI * (days_since - 1.0)) / period).sin();
+
+            if (val_now >= 0.0 && val_prev < 0.0) || (val_now <= 0.0 && val_prev > 0.0) {
+                active.push(name);
+            }
+        }
+        active
+    }
+
+    fn build_values_table(&self) -> Column<'_, Message> {
+        let mut table = column![
+            row![
+                text("Date").size(13).width(90),
+                text("Day").size(13).width(50),
+                text("Physical").size(13).width(80).color(Color::from_rgb8(255, 80, 80)),
+                text("Emotional").size(13).width(80).color(Color::from_rgb8(80, 255, 80)),
+                text("Intellectual").size(13).width(90).color(Color::from_rgb8(80, 80, 255)),
+                text("Average").size(13).width(80),
+                text("Flags").size(13),
+            ].spacing(10)
+        ].spacing(6);
+
+        if let Ok(birthday) = NaiveDate::parse_from_str(&self.date_input, "%Y-%m-%d") {
+            let today = self.today();
+            let target_date = today + Duration::days(self.day_offset as i64);
+            let view_start = target_date - Duration::days(self.window_days as i64 / 2);
+
+            let mut rows = column![].spacing(4);
+            for i in 0..self.window_days {
+                let date = view_start + Duration::days(i as i64);
+                let days_since = date.signed_duration_since(birthday).num_days() as f64;
+
+                let p = ((2.0 * std::f64::consts::PI * days_since) / 23.0).sin();
+                let e = ((2.0 * std::f64::consts::PI * days_since) / 28.0).sin();
+                let intel = ((2.0 * std::f64::consts::PI * days_since) / 33.0).sin();
+                let avg = (p + e + intel) / 3.0;
+
+                let pct = |v: f64| format!("{:+.0}%", v * 100.0);
+                let crossings = Self::cycle_crossings(days_since);
+                let flags = if crossings.is_empty() { String::new() } else { "CRITICAL".to_string() };
+
+                let row_color = if date == target_date { Color::from_rgb(1.0, 1.0, 0.0) } else { Color::WHITE };
+
+                rows = rows.push(
+                    row![
+                        text(date.format("%Y-%m-%d").to_string()).size(13).width(90).color(row_color),
+                        text(date.format("%a").to_string()).size(13).width(50).color(row_color),
+                        text(pct(p)).size(13).width(80),
+                        text(pct(e)).size(13).width(80),
+                        text(pct(intel)).size(13).width(90),
+                        text(pct(avg)).size(13).width(80),
+                        text(flags).size(13).color(Color::from_rgb(1.0, 0.3, 0.3)),
+                    ].spacing(10)
+                );
+            }
+
+            table = table.push(scrollable(rows).height(Length::Fill));
+        }
+        table
+    }
 }
 
 impl<Message> canvas::Program<Message> for BiorhythmApp {
@@ -258,16 +639,22 @@ impl<Message> canvas::Program<Message> for BiorhythmApp {
             let mid_y = pad_t + (chart_h / 2.0);
 
             if let Ok(birthday) = NaiveDate::parse_from_str(&self.date_input, "%Y-%m-%d") {
-                let today = Utc::now().naive_utc().date();
+                let today = self.today();
                 let target_date = today + Duration::days(self.day_offset as i64);
-                let view_start = target_date - Duration::days(15);
+                let view_start = target_date - Duration::days(self.window_days as i64 / 2);
                 let days_at_start = view_start.signed_duration_since(birthday).num_days() as f64;
+                let mut profiles = vec![(days_at_start, 1.0f32)];
+                for entry in &self.active_entries {
+                    let offset = view_start.signed_duration_since(entry.date).num_days() as f64;
+                    profiles.push((offset, 0.35));
+                }
 
                 frame.stroke(&Path::line(Point::new(pad_l, pad_t), Point::new(pad_l, pad_t + chart_h)), Stroke::default().with_color(Color::WHITE).with_width(1.0));
                 frame.stroke(&Path::line(Point::new(pad_l, mid_y), Point::new(pad_l + chart_w, mid_y)), Stroke::default().with_color(Color::from_rgb(0.4, 0.4, 0.4)));
 
-                for i in 0..=30 {
-                    let x = pad_l + (i as f32 / 30.0) * chart_w;
+                let tick_step = (self.window_days / 6).max(1);
+                for i in 0..=self.window_days {
+                    let x = pad_l + (i as f32 / self.window_days as f32) * chart_w;
                     let cur_date = view_start + Duration::days(i as i64);
                     let is_target = cur_date == target_date;
                     // Check if the current date in the loop is a Sunday
@@ -276,7 +663,7 @@ impl<Message> canvas::Program<Message> for BiorhythmApp {
                     let l_col = if is_target { Color::from_rgba(1.0, 1.0, 0.0, 0.8) } else { Color::from_rgba(1.0, 1.0, 1.0, 0.05) };
                     frame.stroke(&Path::line(Point::new(x, pad_t), Point::new(x, pad_t + chart_h)), Stroke::default().with_color(l_col));
 
-                    if is_target || i % 5 == 0 {
+                    if is_target || i % tick_step == 0 {
                         frame.fill_text(Text {
                             content: cur_date.format("%d/%m").to_string(),
                             position: Point::new(x, pad_t - 15.0),
@@ -307,8 +694,10 @@ impl<Message> canvas::Program<Message> for BiorhythmApp {
                     });
                 }
 
+                self.draw_events(frame, view_start, pad_l, chart_w, pad_t, chart_h);
                 self.draw_bars(frame, days_at_start, pad_l, chart_w, mid_y, chart_h);
-                self.draw_plot(frame, days_at_start, pad_l, chart_w, mid_y, chart_h);
+                self.draw_plot(frame, &profiles, pad_l, chart_w, mid_y, chart_h);
+                self.draw_journal(frame, view_start, pad_l, chart_w, mid_y, chart_h);
             }
         });
         vec![geometry]
@@ -317,8 +706,8 @@ impl<Message> canvas::Program<Message> for BiorhythmApp {
 
 impl BiorhythmApp {
     fn draw_bars(&self, frame: &mut Frame, start: f64, pad: f32, w: f32, mid_y: f32, h: f32) {
-        let spacing = w / 30.0;
-        for i in 0..30 {
+        let spacing = w / self.window_days as f32;
+        for i in 0..self.window_days {
             let d = i as f64;
             let p = ((2.0 * std::f64::consts::PI * (start + d)) / 23.0).sin();
             let e = ((2.0 * std::f64::consts::PI * (start + d)) / 28.0).sin();
@@ -336,23 +725,79 @@ impl BiorhythmApp {
         }
     }
 
-    fn draw_plot(&self, frame: &mut Frame, start: f64, pad: f32, w: f32, mid_y: f32, h: f32) {
+    fn draw_events(&self, frame: &mut Frame, view_start: NaiveDate, pad: f32, w: f32, pad_t: f32, h: f32) {
+        let view_end = view_start + Duration::days(self.window_days as i64);
+        let day_w = w / self.window_days as f32;
+
+        for event in &self.events {
+            if !event.is_in_days(view_start, view_end) {
+                continue;
+            }
+
+            let clamped_begin = event.begin.max(view_start);
+            let clamped_end = event.end.min(view_end);
+            let x_begin = pad + (clamped_begin - view_start).num_days() as f32 * day_w;
+            let x_end = (pad + ((clamped_end - view_start).num_days() as f32 + 1.0) * day_w).min(pad + w);
+
+            frame.fill_rectangle(
+                Point::new(x_begin, pad_t),
+                Size::new(x_end - x_begin, h),
+                Color::from_rgba(1.0, 0.8, 0.2, 0.12),
+            );
+
+            frame.fill_text(Text {
+                content: format!("{} ({}d)", event.text, event.span_days()),
+                position: Point::new(x_begin + 4.0, pad_t + 4.0),
+                color: Color::from_rgba(1.0, 0.8, 0.2, 0.9),
+                size: 11.0.into(),
+                horizontal_alignment: alignment::Horizontal::Left,
+                ..Default::default()
+            });
+        }
+    }
+
+    fn draw_journal(&self, frame: &mut Frame, view_start: NaiveDate, pad: f32, w: f32, mid_y: f32, h: f32) {
+        let colors = [Color::from_rgb8(255, 80, 80), Color::from_rgb8(80, 255, 80), Color::from_rgb8(80, 80, 255)];
+        let day_w = w / self.window_days as f32;
+
+        for (date, ratings) in &self.journal {
+            let offset = (*date - view_start).num_days();
+            if offset < 0 || offset > self.window_days as i64 {
+                continue;
+            }
+
+            let x = pad + offset as f32 * day_w;
+            for (idx, color) in colors.into_iter().enumerate() {
+                let val = ratings[idx] as f32 / 100.0;
+                let y = mid_y - (val * (h / 2.0));
+                frame.fill_rectangle(Point::new(x - 2.5, y - 2.5), Size::new(5.0, 5.0), color);
+            }
+        }
+    }
+
+    fn draw_plot(&self, frame: &mut Frame, profiles: &[(f64, f32)], pad: f32, w: f32, mid_y: f32, h: f32) {
+        // One sample per pixel keeps the curve smooth regardless of how wide the window is.
+        let samples = (w.round() as usize).max(self.window_days as usize);
         let cycles = [(23.0, Color::from_rgb8(255, 80, 80)), (28.0, Color::from_rgb8(80, 255, 80)), (33.0, Color::from_rgb8(80, 80, 255))];
-        for (period, col) in cycles {
-            let mut path = canvas::path::Builder::new();
-            for i in 0..=300 {
-                let d_off = (i as f64 / 300.0) * 30.0;
-                let val = ((2.0 * std::f64::consts::PI * (start + d_off)) / period).sin();
-                let x = pad + (i as f32 / 300.0) * w;
-                let y = mid_y - (val as f32 * (h / 2.0));
-
-                if val.abs() < 0.015 {
-                    frame.fill_rectangle(Point::new(x - 3.0, mid_y - 3.0), Size::new(6.0, 6.0), Color::WHITE);
-                }
+        for (start, alpha) in profiles.iter().copied() {
+            let is_primary = alpha >= 1.0;
+            for (period, col) in cycles {
+                let draw_col = Color::from_rgba(col.r, col.g, col.b, alpha);
+                let mut path = canvas::path::Builder::new();
+                for i in 0..=samples {
+                    let d_off = (i as f64 / samples as f64) * self.window_days as f64;
+                    let val = ((2.0 * std::f64::consts::PI * (start + d_off)) / period).sin();
+                    let x = pad + (i as f32 / samples as f32) * w;
+                    let y = mid_y - (val as f32 * (h / 2.0));
+
+                    if is_primary && val.abs() < 0.015 {
+                        frame.fill_rectangle(Point::new(x - 3.0, mid_y - 3.0), Size::new(6.0, 6.0), Color::WHITE);
+                    }
 
-                if i == 0 { path.move_to(Point::new(x, y)); } else { path.line_to(Point::new(x, y)); }
+                    if i == 0 { path.move_to(Point::new(x, y)); } else { path.line_to(Point::new(x, y)); }
+                }
+                frame.stroke(&path.build(), Stroke::default().with_color(draw_col).with_width(if is_primary { 2.5 } else { 1.5 }));
             }
-            frame.stroke(&path.build(), Stroke::default().with_color(col).with_width(2.5));
         }
     }
 }
@@ -360,6 +805,9 @@ impl BiorhythmApp {
 impl Default for BiorhythmApp {
     fn default() -> Self {
         let saved_entries = fs::read_to_string("entries.json").ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
+        let events = fs::read_to_string("events.json").ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
+        let settings: AppSettings = fs::read_to_string("settings.json").ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
+        let journal = fs::read_to_string("journal.json").ok().and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
         Self {
             name_input: String::new(),
             date_input: Utc::now().naive_utc().date().format("%Y-%m-%d").to_string(),
@@ -369,6 +817,18 @@ impl Default for BiorhythmApp {
             day_offset: 0,
             rolling_direction: None,
             last_tick: Instant::now(),
+            view_mode: ViewMode::Graph,
+            window_days: 30,
+            event_text_input: String::new(),
+            event_begin_input: String::new(),
+            event_end_input: String::new(),
+            events,
+            tz_offset_minutes: settings.tz_offset_minutes,
+            journal,
+            rating_physical_input: String::new(),
+            rating_emotional_input: String::new(),
+            rating_intellectual_input: String::new(),
+            active_entries: Vec::new(),
         }
     }
 }